@@ -64,6 +64,13 @@ macro_rules! register_map {
     };
 }
 
+/// Converts a physical value into the nearest representable register code,
+/// saturating at both ends of the `[base, base + max_code * step]` range.
+fn quantize(value: u16, base: u16, step: u16, max_code: u8) -> u8 {
+    let steps = (value.saturating_sub(base) as u32 + step as u32 / 2) / step as u32;
+    steps.min(max_code as u32) as u8
+}
+
 register_map!(
     InputSourceControl: 0x00, RW,
     PowerOnConfiguration: 0x01, RW,
@@ -215,6 +222,31 @@ impl Default for ChargeVoltageControl {
     }
 }
 
+impl ChargeVoltageControl {
+    const REGULATION_BASE_MV: u16 = 3600;
+    const REGULATION_STEP_MV: u16 = 15;
+    const REGULATION_MAX_CODE: u8 = 0x3f;
+
+    /// Battery regulation voltage in millivolts.
+    pub fn regulation_millivolts(&self) -> u16 {
+        Self::REGULATION_BASE_MV + Self::REGULATION_STEP_MV * self.regulation_voltage() as u16
+    }
+
+    /// Sets the battery regulation voltage in millivolts, clamping to
+    /// `[3600, 4545]` and rounding to the nearest 15 mV step. Returns the
+    /// code that was written.
+    pub fn set_regulation_millivolts(&mut self, millivolts: u16) -> u8 {
+        let code = quantize(
+            millivolts,
+            Self::REGULATION_BASE_MV,
+            Self::REGULATION_STEP_MV,
+            Self::REGULATION_MAX_CODE,
+        );
+        self.set_regulation_voltage(code);
+        code
+    }
+}
+
 #[derive(BitfieldSpecifier, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum TerminalCurrent {
     I24mA,
@@ -242,6 +274,31 @@ impl Default for DischargeAndTerminationCurrent {
     }
 }
 
+impl DischargeAndTerminationCurrent {
+    const DISCHARGE_BASE_MA: u16 = 200;
+    const DISCHARGE_STEP_MA: u16 = 200;
+    const DISCHARGE_MAX_CODE: u8 = 0x0f;
+
+    /// Discharge current limit in milliamps.
+    pub fn discharge_current_limit_milliamps(&self) -> u16 {
+        Self::DISCHARGE_BASE_MA + Self::DISCHARGE_STEP_MA * self.discharge_current_limit() as u16
+    }
+
+    /// Sets the discharge current limit in milliamps, clamping to
+    /// `[200, 3200]` and rounding to the nearest 200 mA step. Returns the
+    /// code that was written.
+    pub fn set_discharge_current_limit_milliamps(&mut self, milliamps: u16) -> u8 {
+        let code = quantize(
+            milliamps,
+            Self::DISCHARGE_BASE_MA,
+            Self::DISCHARGE_STEP_MA,
+            Self::DISCHARGE_MAX_CODE,
+        );
+        self.set_discharge_current_limit(code);
+        code
+    }
+}
+
 #[bitfield]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct ChargeCurrentControl {
@@ -258,6 +315,31 @@ impl Default for ChargeCurrentControl {
     }
 }
 
+impl ChargeCurrentControl {
+    const CHARGE_CURRENT_BASE_MA: u16 = 100;
+    const CHARGE_CURRENT_STEP_MA: u16 = 20;
+    const CHARGE_CURRENT_MAX_CODE: u8 = 0x1f;
+
+    /// Fast charge current in milliamps.
+    pub fn charge_current_milliamps(&self) -> u16 {
+        Self::CHARGE_CURRENT_BASE_MA + Self::CHARGE_CURRENT_STEP_MA * self.charge_current() as u16
+    }
+
+    /// Sets the fast charge current in milliamps, clamping to `[100, 720]`
+    /// and rounding to the nearest 20 mA step. Returns the code that was
+    /// written.
+    pub fn set_charge_current_milliamps(&mut self, milliamps: u16) -> u8 {
+        let code = quantize(
+            milliamps,
+            Self::CHARGE_CURRENT_BASE_MA,
+            Self::CHARGE_CURRENT_STEP_MA,
+            Self::CHARGE_CURRENT_MAX_CODE,
+        );
+        self.set_charge_current(code);
+        code
+    }
+}
+
 #[derive(BitfieldSpecifier, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum UVLOThreshold {
     U2400mV,
@@ -316,3 +398,29 @@ impl Default for InputSourceControl {
         }
     }
 }
+
+impl InputSourceControl {
+    const INPUT_MINIMUM_BASE_MV: u16 = 3880;
+    const INPUT_MINIMUM_STEP_MV: u16 = 80;
+    const INPUT_MINIMUM_MAX_CODE: u8 = 0x0f;
+
+    /// Input voltage DPM threshold in millivolts.
+    pub fn input_minimum_millivolts(&self) -> u16 {
+        Self::INPUT_MINIMUM_BASE_MV
+            + Self::INPUT_MINIMUM_STEP_MV * self.input_minimum_voltage() as u16
+    }
+
+    /// Sets the input voltage DPM threshold in millivolts, clamping to
+    /// `[3880, 5080]` and rounding to the nearest 80 mV step. Returns the
+    /// code that was written.
+    pub fn set_input_minimum_millivolts(&mut self, millivolts: u16) -> u8 {
+        let code = quantize(
+            millivolts,
+            Self::INPUT_MINIMUM_BASE_MV,
+            Self::INPUT_MINIMUM_STEP_MV,
+            Self::INPUT_MINIMUM_MAX_CODE,
+        );
+        self.set_input_minimum_voltage(code);
+        code
+    }
+}